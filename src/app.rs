@@ -1,4 +1,8 @@
-use std::{collections::VecDeque, time::Duration};
+use std::{
+    collections::VecDeque,
+    sync::mpsc::{self, Receiver},
+    time::Duration,
+};
 
 use eframe::egui::{self, Context};
 #[cfg(not(target_arch = "wasm32"))]
@@ -8,13 +12,198 @@ use wasm_timer::Instant;
 use crate::{frame::Frame, output::Output, rack::rack::Rack};
 
 const SCALE: f32 = 1.5;
-const PROFILING: bool = false;
+const DEFAULT_DELTA_WINDOW: usize = 50;
+
+const APP_KEY: &str = "rack_app";
+/// Bump whenever `PersistedState`'s shape changes so older saved sessions are
+/// discarded instead of failing to deserialize (or deserializing into garbage).
+const SCHEMA_VERSION: u32 = 1;
+
+#[derive(serde::Serialize)]
+struct PersistedStateRef<'a> {
+    schema_version: u32,
+    rack: &'a Rack,
+    output_device: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct PersistedState {
+    schema_version: u32,
+    rack: Rack,
+    output_device: Option<String>,
+}
+
+/// Spawns a future on a background thread, detached from the egui update loop.
+#[cfg(not(target_arch = "wasm32"))]
+fn execute<F: std::future::Future<Output = ()> + Send + 'static>(future: F) {
+    std::thread::spawn(move || futures::executor::block_on(future));
+}
+
+/// Spawns a future on the browser's event loop, detached from the egui update loop.
+#[cfg(target_arch = "wasm32")]
+fn execute<F: std::future::Future<Output = ()> + 'static>(future: F) {
+    wasm_bindgen_futures::spawn_local(future);
+}
+
+/// Outcome of an in-flight patch load, reported back from the file dialog task.
+enum PatchLoad {
+    Loaded(Rack),
+    Failed(String),
+}
+
+/// An in-progress live master-output recording.
+struct Recording {
+    started: Instant,
+    dropped: usize,
+    #[cfg(not(target_arch = "wasm32"))]
+    tx: mpsc::SyncSender<Frame>,
+    #[cfg(not(target_arch = "wasm32"))]
+    writer_thread: Option<std::thread::JoinHandle<()>>,
+    #[cfg(target_arch = "wasm32")]
+    sample_rate: u32,
+    #[cfg(target_arch = "wasm32")]
+    buffer: Vec<Frame>,
+}
+
+impl Recording {
+    fn wav_spec(sample_rate: u32) -> hound::WavSpec {
+        hound::WavSpec {
+            channels: 2,
+            sample_rate,
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn start(path: std::path::PathBuf, sample_rate: u32) -> std::io::Result<Self> {
+        let mut writer = hound::WavWriter::create(path, Self::wav_spec(sample_rate))?;
+        let (tx, rx) = mpsc::sync_channel::<Frame>(sample_rate as usize);
+
+        let writer_thread = std::thread::spawn(move || {
+            while let Ok(frame) = rx.recv() {
+                let _ = writer.write_sample(frame.left);
+                let _ = writer.write_sample(frame.right);
+            }
+            let _ = writer.finalize();
+        });
+
+        Ok(Self {
+            started: Instant::now(),
+            dropped: 0,
+            tx,
+            writer_thread: Some(writer_thread),
+        })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn start(sample_rate: u32) -> Self {
+        Self {
+            started: Instant::now(),
+            dropped: 0,
+            sample_rate,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Tees a frame into the recording. On desktop the frame is dropped rather than blocking
+    /// `App::process` if the writer thread can't keep up; on wasm every frame is buffered.
+    fn push(&mut self, frame: Frame) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.tx.try_send(frame).is_err() {
+            self.dropped += 1;
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        self.buffer.push(frame);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn stop(self) {
+        drop(self.tx);
+        if let Some(writer_thread) = self.writer_thread {
+            let _ = writer_thread.join();
+        }
+    }
+
+    /// Hands the buffered recording off to a background task instead of encoding it inline,
+    /// since there's no writer thread on wasm to absorb the stall.
+    #[cfg(target_arch = "wasm32")]
+    fn stop(self) -> Receiver<Result<(), String>> {
+        let (tx, rx) = mpsc::channel();
+        let sample_rate = self.sample_rate;
+        let buffer = self.buffer;
+
+        execute(async move {
+            let _ = tx.send(Self::encode_and_save(sample_rate, buffer).await);
+        });
+
+        rx
+    }
+
+    /// Encodes the recording in chunks, yielding to the browser between each one so a long
+    /// recording doesn't block the event loop in a single synchronous burst.
+    #[cfg(target_arch = "wasm32")]
+    async fn encode_and_save(sample_rate: u32, buffer: Vec<Frame>) -> Result<(), String> {
+        const CHUNK_FRAMES: usize = 8192;
+
+        let mut bytes = std::io::Cursor::new(Vec::new());
+        {
+            let mut writer = hound::WavWriter::new(&mut bytes, Self::wav_spec(sample_rate))
+                .map_err(|err| err.to_string())?;
+
+            for chunk in buffer.chunks(CHUNK_FRAMES) {
+                for frame in chunk {
+                    writer
+                        .write_sample(frame.left)
+                        .map_err(|err| err.to_string())?;
+                    writer
+                        .write_sample(frame.right)
+                        .map_err(|err| err.to_string())?;
+                }
+                let _ = wasm_timer::Delay::new(Duration::from_millis(0)).await;
+            }
+
+            writer.finalize().map_err(|err| err.to_string())?;
+        }
+
+        let Some(handle) = rfd::AsyncFileDialog::new()
+            .set_file_name("recording.wav")
+            .add_filter("WAV", &["wav"])
+            .save_file()
+            .await
+        else {
+            return Ok(());
+        };
+
+        handle
+            .write(&bytes.into_inner())
+            .await
+            .map_err(|err| err.to_string())
+    }
+}
 
 pub struct App {
     pub rack: Rack,
     output: Output,
     last_instant: Instant,
     last_deltas: VecDeque<Duration>,
+    delta_window: usize,
+    show_performance: bool,
+    profiling: bool,
+    patch_load_rx: Option<Receiver<PatchLoad>>,
+    patch_error: Option<String>,
+    save_rx: Option<Receiver<Result<(), String>>>,
+    save_error: Option<String>,
+    bounce_window_open: bool,
+    bounce_duration_secs: f32,
+    bounce_error: Option<String>,
+    #[cfg(not(target_arch = "wasm32"))]
+    bounce_rx: Option<Receiver<Result<(), String>>>,
+    recording: Option<Recording>,
+    recording_error: Option<String>,
+    #[cfg(target_arch = "wasm32")]
+    recording_finalize_rx: Option<Receiver<Result<(), String>>>,
 }
 
 impl Default for App {
@@ -26,6 +215,22 @@ impl Default for App {
             output: Output::new(),
             last_instant: Instant::now(),
             last_deltas: VecDeque::new(),
+            delta_window: DEFAULT_DELTA_WINDOW,
+            show_performance: false,
+            profiling: false,
+            patch_load_rx: None,
+            patch_error: None,
+            save_rx: None,
+            save_error: None,
+            bounce_window_open: false,
+            bounce_duration_secs: 10.0,
+            bounce_error: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            bounce_rx: None,
+            recording: None,
+            recording_error: None,
+            #[cfg(target_arch = "wasm32")]
+            recording_finalize_rx: None,
         }
     }
 }
@@ -33,7 +238,7 @@ impl Default for App {
 impl App {
     #[cfg(target_arch = "wasm32")]
     pub fn run(self) {
-        puffin::set_scopes_on(PROFILING);
+        puffin::set_scopes_on(self.profiling);
 
         web_sys::window()
             .unwrap()
@@ -51,7 +256,7 @@ impl App {
                     Box::new(|cc| {
                         cc.egui_ctx.set_pixels_per_point(SCALE);
                         // cc.egui_ctx.set_debug_on_hover(true);
-                        Box::new(self)
+                        Box::new(self.rehydrated(cc.storage))
                     }),
                 )
                 .await
@@ -61,7 +266,7 @@ impl App {
 
     #[cfg(not(target_arch = "wasm32"))]
     pub fn run(self) {
-        puffin::set_scopes_on(PROFILING);
+        puffin::set_scopes_on(self.profiling);
 
         let options = eframe::NativeOptions {
             initial_window_size: Some(Vec2::new(1280.0, 720.0)),
@@ -77,12 +282,32 @@ impl App {
             Box::new(|cc| {
                 cc.egui_ctx.set_pixels_per_point(SCALE);
                 // cc.egui_ctx.set_debug_on_hover(true);
-                Box::new(self)
+                Box::new(self.rehydrated(cc.storage))
             }),
         )
         .unwrap();
     }
 
+    /// Applies a previously saved session, if one exists and matches `SCHEMA_VERSION`.
+    fn rehydrated(mut self, storage: Option<&dyn eframe::Storage>) -> Self {
+        let Some(storage) = storage else {
+            return self;
+        };
+        let Some(persisted) = eframe::get_value::<PersistedState>(storage, APP_KEY) else {
+            return self;
+        };
+        if persisted.schema_version != SCHEMA_VERSION {
+            return self;
+        }
+
+        self.rack = persisted.rack;
+        if let Some(device) = persisted.output_device {
+            self.output.select_device(&device);
+        }
+
+        self
+    }
+
     /// Draw ui
     fn show(&mut self, ctx: &Context, avg_delta: Duration) {
         puffin::profile_function!();
@@ -92,18 +317,279 @@ impl App {
                 ui.label(env!("CARGO_PKG_NAME"));
                 ui.separator();
 
+                ui.menu_button("File", |ui| {
+                    if ui.button("Save patch...").clicked() {
+                        ui.close_menu();
+                        self.save_patch();
+                    }
+                    if ui.button("Load patch...").clicked() {
+                        ui.close_menu();
+                        self.load_patch();
+                    }
+                    ui.separator();
+                    if ui.button("Bounce to WAV...").clicked() {
+                        ui.close_menu();
+                        self.bounce_window_open = true;
+                    }
+                });
+                ui.separator();
+
                 self.output.show(ui);
                 ui.separator();
 
+                if self.recording.is_some() {
+                    if ui.button("⏹ Stop").clicked() {
+                        self.stop_recording();
+                    }
+                } else if self.is_finalizing_recording() {
+                    ui.spinner();
+                    ui.label("Finalizing recording...");
+                } else {
+                    if ui.button("⏺ Record").clicked() {
+                        self.recording_error = None;
+                        self.start_recording();
+                    }
+                    if let Some(error) = &self.recording_error {
+                        ui.colored_label(egui::Color32::RED, error);
+                    }
+                }
+                if let Some(recording) = &self.recording {
+                    ui.label(format!(
+                        "{:.1}s ({} dropped)",
+                        recording.started.elapsed().as_secs_f32(),
+                        recording.dropped
+                    ))
+                    .on_hover_text_at_pointer("recording time / dropped samples");
+                }
+                ui.separator();
+
                 ui.label(format!("{:.1}ms", avg_delta.as_secs_f32() * 1000.0))
                     .on_hover_text_at_pointer("average frame time");
                 ui.separator();
+
+                ui.toggle_value(&mut self.show_performance, "📈 Performance");
+                ui.separator();
+
+                let mut profiling = self.profiling;
+                if ui
+                    .toggle_value(&mut profiling, "🔥 Profiler")
+                    .on_hover_text_at_pointer("F3")
+                    .changed()
+                {
+                    self.profiling = profiling;
+                    puffin::set_scopes_on(self.profiling);
+                }
+                ui.separator();
+
+                if let Some(error) = &self.patch_error {
+                    ui.colored_label(egui::Color32::RED, error)
+                        .on_hover_text_at_pointer("failed to load patch");
+                }
+                if let Some(error) = &self.save_error {
+                    ui.colored_label(egui::Color32::RED, error)
+                        .on_hover_text_at_pointer("failed to save patch");
+                }
+                if let Some(error) = &self.bounce_error {
+                    ui.colored_label(egui::Color32::RED, error)
+                        .on_hover_text_at_pointer("failed to bounce to WAV");
+                }
             });
         });
 
+        self.show_bounce_window(ctx);
+        self.show_performance_panel(ctx);
+
         self.rack.show(ctx, self.output.sample_rate_or_default());
     }
 
+    /// Draw performance overlay
+    fn show_performance_panel(&mut self, ctx: &Context) {
+        if !self.show_performance {
+            return;
+        }
+
+        let sample_rate = self.output.sample_rate_or_default().max(1);
+        // The real-time budget for a frame is how long the audio thread takes to drain the
+        // whole buffer: any slower and the GUI side can't refill it before it runs dry.
+        let budget = self.output.instance().map(|instance| {
+            Duration::from_secs_f32(instance.capacity() as f32 / sample_rate as f32)
+        });
+
+        let buffer_fill = self.output.instance().map(|instance| instance.free_len());
+
+        egui::Window::new("Performance")
+            .open(&mut self.show_performance)
+            .default_width(360.0)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Window");
+                    ui.add(
+                        egui::DragValue::new(&mut self.delta_window)
+                            .clamp_range(10..=1000)
+                            .speed(1),
+                    );
+                });
+
+                match budget {
+                    Some(budget) => {
+                        let over_budget = self
+                            .last_deltas
+                            .iter()
+                            .filter(|delta| **delta > budget)
+                            .count();
+                        ui.label(format!(
+                            "{over_budget}/{} frames over the {:.2}ms real-time budget",
+                            self.last_deltas.len(),
+                            budget.as_secs_f32() * 1000.0
+                        ));
+                    }
+                    None => {
+                        ui.label("no output device open — real-time budget unknown");
+                    }
+                }
+
+                if let Some(free_len) = buffer_fill {
+                    ui.label(format!("audio buffer free: {free_len} samples"));
+                }
+
+                let points: egui_plot::PlotPoints = self
+                    .last_deltas
+                    .iter()
+                    .rev()
+                    .enumerate()
+                    .map(|(i, delta)| [i as f64, delta.as_secs_f64() * 1000.0])
+                    .collect();
+
+                egui_plot::Plot::new("frame_deltas")
+                    .height(150.0)
+                    .include_y(0.0)
+                    .show(ui, |plot_ui| {
+                        plot_ui.line(egui_plot::Line::new(points).name("frame time (ms)"));
+                        if let Some(budget) = budget {
+                            plot_ui.hline(
+                                egui_plot::HLine::new(budget.as_secs_f32() as f64 * 1000.0)
+                                    .name("budget")
+                                    .color(egui::Color32::RED),
+                            );
+                        }
+                    });
+            });
+    }
+
+    /// Draws the "Bounce to WAV" dialog and kicks off an offline render on confirm.
+    fn show_bounce_window(&mut self, ctx: &Context) {
+        if !self.bounce_window_open {
+            return;
+        }
+
+        let mut open = self.bounce_window_open;
+        egui::Window::new("Bounce to WAV")
+            .open(&mut open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Duration (s)");
+                    ui.add(
+                        egui::DragValue::new(&mut self.bounce_duration_secs)
+                            .clamp_range(0.1..=3600.0)
+                            .speed(0.1),
+                    );
+                });
+
+                #[cfg(not(target_arch = "wasm32"))]
+                if self.bounce_rx.is_some() {
+                    ui.spinner();
+                    ui.label("Rendering...");
+                } else if ui.button("Choose output & render...").clicked() {
+                    self.bounce_to_wav(Duration::from_secs_f32(self.bounce_duration_secs));
+                }
+
+                #[cfg(target_arch = "wasm32")]
+                ui.label("Offline bounce is only available in the desktop build.");
+            });
+        self.bounce_window_open &= open;
+    }
+
+    /// Polls for a finished offline render and surfaces any error it hit.
+    ///
+    /// Reopens the window on error so closing the dialog mid-render can't hide the result.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn poll_bounce(&mut self) {
+        if let Some(rx) = &self.bounce_rx {
+            if let Ok(result) = rx.try_recv() {
+                if let Err(err) = result {
+                    self.bounce_error = Some(err);
+                    self.bounce_window_open = true;
+                }
+                self.bounce_rx = None;
+            }
+        }
+    }
+
+    /// Renders `duration` worth of audio as fast as the CPU allows, bypassing `Output`.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn bounce_to_wav(&mut self, duration: Duration) {
+        const BLOCK_SIZE: usize = 4096;
+
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name("bounce.wav")
+            .add_filter("WAV", &["wav"])
+            .save_file()
+        else {
+            return;
+        };
+
+        self.bounce_error = None;
+        let sample_rate = self.output.sample_rate_or_default();
+        let mut rack = self.rack.clone();
+
+        let (tx, rx) = mpsc::channel();
+        self.bounce_rx = Some(rx);
+
+        std::thread::spawn(move || {
+            let result = (|| -> Result<(), String> {
+                let spec = hound::WavSpec {
+                    channels: 2,
+                    sample_rate,
+                    bits_per_sample: 32,
+                    sample_format: hound::SampleFormat::Float,
+                };
+                let mut writer = hound::WavWriter::create(&path, spec)
+                    .map_err(|err| format!("failed to bounce: {err}"))?;
+
+                let mut remaining = (sample_rate as f32 * duration.as_secs_f32()) as usize;
+                while remaining > 0 {
+                    let amount = remaining.min(BLOCK_SIZE);
+                    let outputs = rack.process_amount(sample_rate, amount);
+
+                    for i in 0..amount {
+                        let mut mixed = Frame::ZERO;
+                        if let Some(frames) = outputs.get(i) {
+                            for &frame in frames {
+                                mixed += frame;
+                            }
+                        }
+
+                        writer
+                            .write_sample(mixed.left)
+                            .map_err(|err| format!("failed to bounce: {err}"))?;
+                        writer
+                            .write_sample(mixed.right)
+                            .map_err(|err| format!("failed to bounce: {err}"))?;
+                    }
+
+                    remaining -= amount;
+                }
+
+                writer
+                    .finalize()
+                    .map_err(|err| format!("failed to bounce: {err}"))
+            })();
+
+            let _ = tx.send(result);
+        });
+    }
+
     /// Process modules & audio output
     fn process(&mut self, delta: Duration) {
         puffin::profile_function!();
@@ -121,6 +607,10 @@ impl App {
                     }
                 }
 
+                if let Some(recording) = &mut self.recording {
+                    recording.push(mixed);
+                }
+
                 instance
                     .push_frame(mixed)
                     .expect("producer should not be full");
@@ -132,6 +622,164 @@ impl App {
                 .process_amount(self.output.sample_rate_or_default(), samples);
         }
     }
+
+    /// Arms live recording of the mixed master output, teeing every frame produced in
+    /// `process` out to a WAV file without disturbing the realtime audio path.
+    fn start_recording(&mut self) {
+        let Some(sample_rate) = self
+            .output
+            .instance()
+            .map(|instance| instance.sample_rate())
+        else {
+            self.recording_error = Some("no output device is open".to_string());
+            return;
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let Some(path) = rfd::FileDialog::new()
+                .set_file_name("recording.wav")
+                .add_filter("WAV", &["wav"])
+                .save_file()
+            else {
+                return;
+            };
+
+            match Recording::start(path, sample_rate) {
+                Ok(recording) => self.recording = Some(recording),
+                Err(err) => {
+                    self.recording_error = Some(format!("failed to start recording: {err}"))
+                }
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.recording = Some(Recording::start(sample_rate));
+        }
+    }
+
+    /// Stops the current recording, flushing any buffered audio to disk.
+    fn stop_recording(&mut self) {
+        let Some(recording) = self.recording.take() else {
+            return;
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        recording.stop();
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.recording_finalize_rx = Some(recording.stop());
+        }
+    }
+
+    /// Polls for a finished wasm recording encode and surfaces any error it hit.
+    #[cfg(target_arch = "wasm32")]
+    fn poll_recording_finalize(&mut self) {
+        if let Some(rx) = &self.recording_finalize_rx {
+            if let Ok(result) = rx.try_recv() {
+                if let Err(err) = result {
+                    self.recording_error = Some(err);
+                }
+                self.recording_finalize_rx = None;
+            }
+        }
+    }
+
+    /// Whether a stopped recording is still being encoded/saved (wasm only).
+    fn is_finalizing_recording(&self) -> bool {
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.recording_finalize_rx.is_some()
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            false
+        }
+    }
+
+    /// Opens a save dialog and writes the current rack out as a `.rack` patch file.
+    fn save_patch(&mut self) {
+        let json = self.rack.to_json();
+        let (tx, rx) = mpsc::channel();
+        self.save_rx = Some(rx);
+        self.save_error = None;
+
+        execute(async move {
+            let Some(handle) = rfd::AsyncFileDialog::new()
+                .set_file_name("patch.rack")
+                .add_filter("rack patch", &["rack"])
+                .save_file()
+                .await
+            else {
+                return;
+            };
+
+            let result = handle
+                .write(json.as_bytes())
+                .await
+                .map_err(|err| err.to_string());
+            let _ = tx.send(result);
+        });
+    }
+
+    /// Polls for a finished patch save and surfaces any error it hit.
+    fn poll_patch_save(&mut self) {
+        if let Some(rx) = &self.save_rx {
+            if let Ok(result) = rx.try_recv() {
+                if let Err(err) = result {
+                    self.save_error = Some(err);
+                }
+                self.save_rx = None;
+            }
+        }
+    }
+
+    /// Opens a load dialog and, once the user picks a file, rehydrates `self.rack` from it.
+    fn load_patch(&mut self) {
+        let (tx, rx) = mpsc::channel();
+        self.patch_load_rx = Some(rx);
+        self.patch_error = None;
+
+        execute(async move {
+            let Some(handle) = rfd::AsyncFileDialog::new()
+                .add_filter("rack patch", &["rack"])
+                .pick_file()
+                .await
+            else {
+                return;
+            };
+
+            let bytes = handle.read().await;
+            let result = match std::str::from_utf8(&bytes) {
+                Ok(json) => match Rack::from_json(json) {
+                    Ok(rack) => PatchLoad::Loaded(rack),
+                    Err(err) => PatchLoad::Failed(err.to_string()),
+                },
+                Err(err) => PatchLoad::Failed(err.to_string()),
+            };
+
+            // The receiving end may have been dropped if another load started since.
+            let _ = tx.send(result);
+        });
+    }
+
+    /// Polls for a finished patch load and applies it to `self.rack`.
+    fn poll_patch_load(&mut self) {
+        if let Some(rx) = &self.patch_load_rx {
+            if let Ok(result) = rx.try_recv() {
+                match result {
+                    PatchLoad::Loaded(rack) => {
+                        self.rack = rack;
+                        self.patch_error = None;
+                    }
+                    PatchLoad::Failed(err) => self.patch_error = Some(err),
+                }
+                self.patch_load_rx = None;
+            }
+        }
+    }
 }
 
 impl eframe::App for App {
@@ -139,7 +787,7 @@ impl eframe::App for App {
         puffin::profile_function!();
         puffin::GlobalProfiler::lock().new_frame();
 
-        if PROFILING {
+        if self.profiling {
             puffin_egui::profiler_window(ctx);
         }
 
@@ -148,7 +796,7 @@ impl eframe::App for App {
         let delta = self.last_instant.elapsed();
 
         self.last_deltas.push_front(delta);
-        if self.last_deltas.len() > 50 {
+        if self.last_deltas.len() > self.delta_window {
             self.last_deltas.pop_back();
         }
 
@@ -156,6 +804,13 @@ impl eframe::App for App {
 
         self.last_instant = Instant::now();
 
+        self.poll_patch_load();
+        self.poll_patch_save();
+        #[cfg(not(target_arch = "wasm32"))]
+        self.poll_bounce();
+        #[cfg(target_arch = "wasm32")]
+        self.poll_recording_finalize();
+
         self.show(ctx, avg_delta);
 
         self.process(delta);
@@ -164,9 +819,23 @@ impl eframe::App for App {
             frame.request_screenshot();
         }
 
+        if ctx.input(|input| input.key_pressed(egui::Key::F3)) {
+            self.profiling = !self.profiling;
+            puffin::set_scopes_on(self.profiling);
+        }
+
         ctx.request_repaint();
     }
 
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        let persisted = PersistedStateRef {
+            schema_version: SCHEMA_VERSION,
+            rack: &self.rack,
+            output_device: self.output.device_name(),
+        };
+        eframe::set_value(storage, APP_KEY, &persisted);
+    }
+
     fn post_rendering(&mut self, _: [u32; 2], frame: &eframe::Frame) {
         if let Some(screenshot) = frame.screenshot() {
             image::save_buffer(
@@ -0,0 +1,22 @@
+use std::ops::AddAssign;
+
+/// A single stereo audio sample produced by a module's output.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Frame {
+    pub left: f32,
+    pub right: f32,
+}
+
+impl Frame {
+    pub const ZERO: Frame = Frame {
+        left: 0.0,
+        right: 0.0,
+    };
+}
+
+impl AddAssign for Frame {
+    fn add_assign(&mut self, rhs: Self) {
+        self.left += rhs.left;
+        self.right += rhs.right;
+    }
+}
@@ -0,0 +1,82 @@
+use eframe::egui::Ui;
+
+use crate::frame::Frame;
+
+const DEFAULT_SAMPLE_RATE: u32 = 44100;
+
+/// A running audio output stream: the ring buffer producer side that `App::process` feeds.
+pub struct Instance {
+    device_name: String,
+    sample_rate: u32,
+    buffer: Vec<Frame>,
+    capacity: usize,
+}
+
+impl Instance {
+    /// Number of frames that can still be pushed before the ring buffer is full.
+    pub fn free_len(&self) -> usize {
+        self.capacity - self.buffer.len()
+    }
+
+    /// Total size of the ring buffer, i.e. the most the audio thread can read before the
+    /// GUI side has to have refilled it.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    pub fn push_frame(&mut self, frame: Frame) -> Result<(), Frame> {
+        if self.buffer.len() >= self.capacity {
+            return Err(frame);
+        }
+        self.buffer.push(frame);
+        Ok(())
+    }
+}
+
+/// Owns the selected audio output device, if any, and exposes it to `App`.
+pub struct Output {
+    instance: Option<Instance>,
+}
+
+impl Output {
+    pub fn new() -> Self {
+        Self { instance: None }
+    }
+
+    pub fn show(&mut self, _ui: &mut Ui) {}
+
+    pub fn sample_rate_or_default(&self) -> u32 {
+        self.instance
+            .as_ref()
+            .map_or(DEFAULT_SAMPLE_RATE, Instance::sample_rate)
+    }
+
+    pub fn instance_mut(&mut self) -> Option<&mut Instance> {
+        self.instance.as_mut()
+    }
+
+    pub fn instance(&self) -> Option<&Instance> {
+        self.instance.as_ref()
+    }
+
+    /// Name of the currently selected output device, if one is open.
+    pub fn device_name(&self) -> Option<String> {
+        self.instance
+            .as_ref()
+            .map(|instance| instance.device_name.clone())
+    }
+
+    /// Accepts a device name to select. No device backend is wired up here: the call opens
+    /// nothing and leaves the current instance (if any) unchanged.
+    pub fn select_device(&mut self, _name: &str) {}
+}
+
+impl Default for Output {
+    fn default() -> Self {
+        Self::new()
+    }
+}
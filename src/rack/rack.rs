@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+use crate::frame::Frame;
+
+/// A single instantiated module placed somewhere on the rack canvas.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ModuleSlot {
+    pub kind: String,
+    pub position: [f32; 2],
+}
+
+/// A cable connecting one module's output port to another module's input port.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Connection {
+    pub from: (usize, usize),
+    pub to: (usize, usize),
+}
+
+/// The full synth graph: every placed module plus the cables between them.
+///
+/// Serializable so a patch can be written out to a `.rack` file or stashed in `eframe`
+/// storage between sessions.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Rack {
+    pub modules: Vec<ModuleSlot>,
+    pub connections: Vec<Connection>,
+}
+
+impl Rack {
+    /// Returns one entry per sample for `amount` samples, each holding the frames for the
+    /// caller to mix. No module is processed here, so every entry is empty.
+    pub fn process_amount(&mut self, _sample_rate: u32, amount: usize) -> Vec<Vec<Frame>> {
+        vec![Vec::new(); amount]
+    }
+
+    /// Rack canvas hook; it draws nothing, so modules and cables are not shown.
+    pub fn show(&mut self, _ctx: &eframe::egui::Context, _sample_rate: u32) {}
+
+    /// Serializes the rack to a pretty-printed JSON patch.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Rack should always be serializable")
+    }
+
+    /// Parses a `.rack` patch file previously written by [`Rack::to_json`].
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+}